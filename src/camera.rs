@@ -0,0 +1,67 @@
+use rltk::{Rltk, Point, RGB, DrawBatch, ColorPair};
+use specs::prelude::*;
+use super::{Map, TileType, Position, Renderable};
+
+pub fn get_screen_bounds(ecs: &World, ctx: &mut Rltk) -> (i32, i32, i32, i32) {
+    let player_pos = ecs.fetch::<Point>();
+    let (x_chars, y_chars) = ctx.get_char_size();
+
+    let center_x = (x_chars / 2) as i32;
+    let center_y = (y_chars / 2) as i32;
+
+    let min_x = player_pos.x - center_x;
+    let max_x = min_x + x_chars as i32;
+    let min_y = player_pos.y - center_y;
+    let max_y = min_y + y_chars as i32;
+
+    (min_x, max_x, min_y, max_y)
+}
+
+pub fn render_camera(ecs: &World, ctx: &mut Rltk, draw_batch: &mut DrawBatch) {
+    let map = ecs.fetch::<Map>();
+    let (min_x, max_x, min_y, max_y) = get_screen_bounds(ecs, ctx);
+    let (width, height) = (max_x - min_x, max_y - min_y);
+
+    draw_batch.target(0);
+    draw_batch.cls();
+
+    for ty in min_y..max_y {
+        for tx in min_x..max_x {
+            let x = tx - min_x;
+            let y = ty - min_y;
+
+            if tx >= 0 && tx < map.width && ty >= 0 && ty < map.height {
+                let idx = map.xy_idx(tx, ty);
+                if map.revealed_tiles[idx] {
+                    let (glyph, fg) = tile_glyph(map.tiles[idx]);
+                    draw_batch.set(Point::new(x, y), ColorPair::new(fg, RGB::from_f32(0., 0., 0.)), glyph);
+                }
+            } else {
+                draw_batch.set(Point::new(x, y), ColorPair::new(RGB::named(rltk::GRAY), RGB::from_f32(0., 0., 0.)), rltk::to_cp437(' '));
+            }
+        }
+    }
+
+    draw_batch.target(2);
+    draw_batch.cls();
+
+    let positions = ecs.read_storage::<Position>();
+    let renderables = ecs.read_storage::<Renderable>();
+    for (pos, render) in (&positions, &renderables).join() {
+        let idx = map.xy_idx(pos.x, pos.y);
+        if !map.visible_tiles[idx] { continue; }
+
+        let screen_x = pos.x - min_x;
+        let screen_y = pos.y - min_y;
+        if screen_x >= 0 && screen_x < width && screen_y >= 0 && screen_y < height {
+            draw_batch.set(Point::new(screen_x, screen_y), ColorPair::new(render.fg, render.bg), render.glyph);
+        }
+    }
+}
+
+fn tile_glyph(tile: TileType) -> (rltk::FontCharType, RGB) {
+    match tile {
+        TileType::Floor => (rltk::to_cp437('.'), RGB::from_f32(0.5, 0.5, 0.5)),
+        TileType::Wall => (rltk::to_cp437('#'), RGB::from_f32(0.0, 1.0, 0.0)),
+    }
+}
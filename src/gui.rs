@@ -0,0 +1,67 @@
+use rltk::{Rltk, RGB, VirtualKeyCode};
+use specs::prelude::*;
+use super::{CombatStats, Player, Name, InBackpack};
+
+pub fn draw_ui(ecs: &World, ctx: &mut Rltk) {
+    ctx.draw_box(0, 43, 79, 6, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK));
+
+    let combat_stats = ecs.read_storage::<CombatStats>();
+    let players = ecs.read_storage::<Player>();
+    for (_player, stats) in (&players, &combat_stats).join() {
+        let health = format!(" HP: {} / {} ", stats.hp, stats.max_hp);
+        ctx.print_color(12, 43, RGB::named(rltk::YELLOW), RGB::named(rltk::BLACK), &health);
+    }
+}
+
+#[derive(PartialEq, Copy, Clone)]
+pub enum ItemMenuResult { Cancel, NoResponse, Selected(Entity) }
+
+/// Lists the player's backpack contents and lets the user pick one with a-z.
+pub fn show_inventory(ecs: &World, ctx: &mut Rltk, player_entity: Entity) -> ItemMenuResult {
+    item_menu(ecs, ctx, player_entity, "Inventory")
+}
+
+/// Lists the player's backpack contents for a drop pick, same a-z selection as `show_inventory`.
+pub fn drop_item_menu(ecs: &World, ctx: &mut Rltk, player_entity: Entity) -> ItemMenuResult {
+    item_menu(ecs, ctx, player_entity, "Drop Which Item?")
+}
+
+fn item_menu(ecs: &World, ctx: &mut Rltk, player_entity: Entity, title: &str) -> ItemMenuResult {
+    let names = ecs.read_storage::<Name>();
+    let backpack = ecs.read_storage::<InBackpack>();
+    let entities = ecs.entities();
+
+    let inventory: Vec<(Entity, &Name)> = (&entities, &backpack, &names)
+        .join()
+        .filter(|(_, pack, _)| pack.owner == player_entity)
+        .map(|(entity, _, name)| (entity, name))
+        .collect();
+
+    let count = inventory.len();
+    let y = (25 - (count / 2)) as i32;
+
+    ctx.draw_box(15, y - 2, 31, (count + 3) as i32, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK));
+    ctx.print_color(18, y - 2, RGB::named(rltk::YELLOW), RGB::named(rltk::BLACK), title);
+    ctx.print_color(18, y + count as i32 + 1, RGB::named(rltk::YELLOW), RGB::named(rltk::BLACK), "ESCAPE to cancel");
+
+    for (i, (_entity, name)) in inventory.iter().enumerate() {
+        let row = y + i as i32 + 1;
+        ctx.set(17, row, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), rltk::to_cp437('('));
+        ctx.set(18, row, RGB::named(rltk::YELLOW), RGB::named(rltk::BLACK), 97 + i as rltk::FontCharType);
+        ctx.set(19, row, RGB::named(rltk::WHITE), RGB::named(rltk::BLACK), rltk::to_cp437(')'));
+        ctx.print(21, row, &name.name);
+    }
+
+    match ctx.key {
+        None => ItemMenuResult::NoResponse,
+        Some(VirtualKeyCode::Escape) => ItemMenuResult::Cancel,
+        Some(key) => {
+            let selection = key as i32 - VirtualKeyCode::A as i32;
+            if selection >= 0 && (selection as usize) < count {
+                ItemMenuResult::Selected(inventory[selection as usize].0)
+            } else {
+                ItemMenuResult::NoResponse
+            }
+        }
+    }
+}
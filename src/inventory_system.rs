@@ -0,0 +1,82 @@
+use specs::prelude::*;
+use super::{Name, Position, InBackpack, WantsToPickupItem, WantsToUseItem, WantsToDropItem,
+            CombatStats, ProvidesHealing};
+
+pub struct ItemCollectionSystem {}
+
+impl<'a> System<'a> for ItemCollectionSystem {
+    type SystemData = ( WriteStorage<'a, WantsToPickupItem>,
+                        WriteStorage<'a, Position>,
+                        WriteStorage<'a, InBackpack>);
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (mut wants_pickup, mut positions, mut backpack) = data;
+
+        for pickup in wants_pickup.join() {
+            positions.remove(pickup.item);
+            backpack.insert(pickup.item, InBackpack { owner: pickup.collected_by })
+                .expect("Unable to insert item into backpack");
+        }
+
+        wants_pickup.clear();
+    }
+}
+
+pub struct ItemUseSystem {}
+
+impl<'a> System<'a> for ItemUseSystem {
+    type SystemData = ( Entities<'a>,
+                        WriteStorage<'a, WantsToUseItem>,
+                        ReadStorage<'a, Name>,
+                        ReadStorage<'a, ProvidesHealing>,
+                        WriteStorage<'a, CombatStats>);
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (entities, mut wants_use, names, healing, mut combat_stats) = data;
+
+        for (entity, useitem) in (&entities, &wants_use).join() {
+            let mut consumed = false;
+
+            if let Some(healer) = healing.get(useitem.item) {
+                if let Some(stats) = combat_stats.get_mut(entity) {
+                    stats.hp = i32::min(stats.max_hp, stats.hp + healer.heal_amount);
+                    if let Some(item_name) = names.get(useitem.item) {
+                        let user_name = names.get(entity).map_or("it", |n| n.name.as_str());
+                        println!("{} uses the {}", user_name, item_name.name);
+                    }
+                    consumed = true;
+                }
+            }
+
+            if consumed {
+                entities.delete(useitem.item).expect("Delete failed");
+            }
+        }
+
+        wants_use.clear();
+    }
+}
+
+pub struct ItemDropSystem {}
+
+impl<'a> System<'a> for ItemDropSystem {
+    type SystemData = ( Entities<'a>,
+                        WriteStorage<'a, WantsToDropItem>,
+                        WriteStorage<'a, Position>,
+                        WriteStorage<'a, InBackpack>);
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (entities, mut wants_drop, mut positions, mut backpack) = data;
+
+        for (entity, to_drop) in (&entities, &wants_drop).join() {
+            let mut dropper_pos = Position { x: 0, y: 0 };
+            if let Some(pos) = positions.get(entity) {
+                dropper_pos = *pos;
+            }
+            positions.insert(to_drop.item, dropper_pos).expect("Unable to insert position");
+            backpack.remove(to_drop.item);
+        }
+
+        wants_drop.clear();
+    }
+}
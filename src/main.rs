@@ -12,27 +12,54 @@ pub use rect::Rect;
 mod visibility_system;
 use visibility_system::VisibilitySystem;
 mod monster_ai_system;
-use monster_ai_system::MonsterAI;
+use monster_ai_system::{MonsterAI, MonsterTurnGate};
+mod map_indexing_system;
+use map_indexing_system::MapIndexingSystem;
+mod melee_combat_system;
+use melee_combat_system::MeleeCombatSystem;
+mod damage_system;
+use damage_system::DamageSystem;
+mod inventory_system;
+use inventory_system::{ItemCollectionSystem, ItemUseSystem, ItemDropSystem};
+mod camera;
 mod gui;
+mod map_builders;
 
 const WIDTH: i32 = 80;
 const HEIGHT: i32 = 50;
+const MAPGEN_FRAME_MS: f32 = 75.0;
 
 #[derive(PartialEq, Copy, Clone)]
-pub enum RunState { Paused, Running }
+pub enum RunState { Paused, Running, GameOver, MapGeneration, ShowInventory, ShowDropItem }
 
 pub struct State {
     pub ecs: World,
-    pub runstate: RunState
+    pub runstate: RunState,
+    pub mapgen_history: Vec<Map>,
+    pub mapgen_index: usize,
+    pub mapgen_timer: f32,
 }
 
 impl State {
     fn run_systems(&mut self) {
         let mut vis = VisibilitySystem{};
         vis.run_now(&self.ecs);
+        let mut mapindex = MapIndexingSystem{};
+        mapindex.run_now(&self.ecs);
         let mut mob = MonsterAI{};
         mob.run_now(&self.ecs);
+        let mut melee = MeleeCombatSystem{};
+        melee.run_now(&self.ecs);
+        let mut damage = DamageSystem{};
+        damage.run_now(&self.ecs);
+        let mut pickup = ItemCollectionSystem{};
+        pickup.run_now(&self.ecs);
+        let mut use_item = ItemUseSystem{};
+        use_item.run_now(&self.ecs);
+        let mut drop_item = ItemDropSystem{};
+        drop_item.run_now(&self.ecs);
         self.ecs.maintain();
+        damage_system::delete_the_dead(&mut self.ecs);
     }
 }
 
@@ -40,31 +67,90 @@ impl GameState for State {
     fn tick(&mut self, ctx : &mut Rltk) {
         let mut draw_batch = DrawBatch::new();
 
+        *self.ecs.write_resource::<RunState>() = self.runstate;
+
+        if self.runstate == RunState::MapGeneration {
+            draw_batch.target(0);
+            draw_batch.cls();
+            if !self.mapgen_history.is_empty() {
+                draw_mapgen_snapshot(&self.mapgen_history[self.mapgen_index], &mut draw_batch);
+            }
+
+            self.mapgen_timer += ctx.frame_time_ms;
+            if self.mapgen_timer > MAPGEN_FRAME_MS {
+                self.mapgen_timer = 0.0;
+                self.mapgen_index += 1;
+                if self.mapgen_index >= self.mapgen_history.len() {
+                    self.runstate = RunState::Running;
+                }
+            }
+
+            draw_batch.submit(0).expect("Batch error");
+            render_draw_buffer(ctx).expect("Render error");
+            return;
+        }
+
         if self.runstate == RunState::Running {
             self.run_systems();
-            self.runstate = RunState::Paused;
-        } else {
+            self.runstate = *self.ecs.fetch::<RunState>();
+            if self.runstate == RunState::Running {
+                self.runstate = RunState::Paused;
+            }
+        } else if self.runstate != RunState::GameOver
+            && self.runstate != RunState::ShowInventory
+            && self.runstate != RunState::ShowDropItem
+        {
             self.runstate = player_input(self, ctx);
         }
 
-        draw_map(&self.ecs, &mut draw_batch);
+        camera::render_camera(&self.ecs, ctx, &mut draw_batch);
 
-        let positions = self.ecs.read_storage::<Position>();
-        let renderables = self.ecs.read_storage::<Renderable>();
-        let map = self.ecs.fetch::<Map>();
+        draw_batch.submit(0).expect("Batch error");
+        render_draw_buffer(ctx).expect("Render error");
 
-        draw_batch.target(2);
-        draw_batch.cls();
+        gui::draw_ui(&self.ecs, ctx);
 
-        for (pos, render) in (&positions, &renderables).join() {
-            let idx = map.xy_idx(pos.x, pos.y);
-            if map.visible_tiles[idx] {
-                draw_batch.set(Point::new(pos.x, pos.y), ColorPair::new(render.fg, render.bg), render.glyph);
+        // Menus draw with immediate ctx calls, so they must come after
+        // render_draw_buffer or the camera's batch clear wipes them out.
+        if self.runstate == RunState::ShowInventory {
+            let player_entity = *self.ecs.fetch::<Entity>();
+            match gui::show_inventory(&self.ecs, ctx, player_entity) {
+                gui::ItemMenuResult::Cancel => self.runstate = RunState::Paused,
+                gui::ItemMenuResult::NoResponse => {}
+                gui::ItemMenuResult::Selected(item) => {
+                    let mut wants_use = self.ecs.write_storage::<WantsToUseItem>();
+                    wants_use.insert(player_entity, WantsToUseItem { item }).expect("Unable to insert use intent");
+                    drop(wants_use);
+                    self.runstate = RunState::Running;
+                }
+            }
+        } else if self.runstate == RunState::ShowDropItem {
+            let player_entity = *self.ecs.fetch::<Entity>();
+            match gui::drop_item_menu(&self.ecs, ctx, player_entity) {
+                gui::ItemMenuResult::Cancel => self.runstate = RunState::Paused,
+                gui::ItemMenuResult::NoResponse => {}
+                gui::ItemMenuResult::Selected(item) => {
+                    let mut wants_drop = self.ecs.write_storage::<WantsToDropItem>();
+                    wants_drop.insert(player_entity, WantsToDropItem { item }).expect("Unable to insert drop intent");
+                    drop(wants_drop);
+                    self.runstate = RunState::Running;
+                }
             }
         }
+    }
+}
 
-        draw_batch.submit(0).expect("Batch error");
-        render_draw_buffer(ctx).expect("Render error");
+fn draw_mapgen_snapshot(map: &Map, draw_batch: &mut DrawBatch) {
+    for y in 0..map.height {
+        for x in 0..map.width {
+            let idx = map.xy_idx(x, y);
+            if !map.revealed_tiles[idx] { continue; }
+            let glyph = match map.tiles[idx] {
+                TileType::Wall => rltk::to_cp437('#'),
+                TileType::Floor => rltk::to_cp437('.'),
+            };
+            draw_batch.set(Point::new(x, y), ColorPair::new(RGB::from_f32(0.5, 0.5, 0.5), RGB::from_f32(0., 0., 0.)), glyph);
+        }
     }
 }
 
@@ -86,7 +172,10 @@ fn main() -> rltk::BError {
 
     let mut gs = State{
         ecs: World::new(),
-        runstate: RunState::Running
+        runstate: RunState::MapGeneration,
+        mapgen_history: Vec::new(),
+        mapgen_index: 0,
+        mapgen_timer: 0.0,
     };
 
     gs.ecs.register::<Position>();
@@ -95,11 +184,27 @@ fn main() -> rltk::BError {
     gs.ecs.register::<Monster>();
     gs.ecs.register::<Viewshed>();
     gs.ecs.register::<Name>();
+    gs.ecs.register::<CombatStats>();
+    gs.ecs.register::<WantsToMelee>();
+    gs.ecs.register::<SufferDamage>();
+    gs.ecs.register::<BlocksTile>();
+    gs.ecs.register::<Item>();
+    gs.ecs.register::<InBackpack>();
+    gs.ecs.register::<WantsToPickupItem>();
+    gs.ecs.register::<WantsToUseItem>();
+    gs.ecs.register::<WantsToDropItem>();
+    gs.ecs.register::<ProvidesHealing>();
 
-    let map : Map = Map::new_map_rooms_and_corridors();
-    let (player_x, player_y) = map.rooms[0].center();
+    let mut rng = rltk::RandomNumberGenerator::new();
+    let mut builder = map_builders::random_builder(WIDTH, HEIGHT);
+    builder.build_map(&mut rng);
+    let map = builder.build_data.map.clone();
+    let (player_x, player_y) = builder.build_data.starting_position
+        .map(|pos| (pos.x, pos.y))
+        .unwrap_or_else(|| map.rooms[0].center());
+    gs.mapgen_history = builder.build_data.history.clone();
 
-    gs.ecs
+    let player_entity = gs.ecs
         .create_entity()
         .with(Position { x: player_x, y: player_y })
         .with(Renderable {
@@ -109,6 +214,8 @@ fn main() -> rltk::BError {
         })
         .with(Player{})
         .with(Viewshed{ visible_tiles: Vec::new(), range: 8, dirty: true })
+        .with(Name{ name: "Player".to_string() })
+        .with(CombatStats{ max_hp: 30, hp: 30, defense: 2, power: 5 })
         .build();
 
     for (i, room) in map.rooms.iter().skip(1).enumerate() {
@@ -123,10 +230,67 @@ fn main() -> rltk::BError {
             .with(Viewshed{ visible_tiles : Vec::new(), range: 8, dirty: true })
             .with(Monster{})
             .with(Name{ name: format!("{} #{}", "Goblin", i) })
+            .with(BlocksTile{})
+            .with(CombatStats{ max_hp: 16, hp: 16, defense: 1, power: 4 })
+            .build();
+    }
+
+    for (i, (idx, name)) in builder.build_data.spawn_list.iter().enumerate() {
+        let x = *idx as i32 % map.width;
+        let y = *idx as i32 / map.width;
+
+        if name == "Goblin" {
+            gs.ecs.create_entity()
+                .with(Position{ x, y })
+                .with(Renderable{
+                    glyph: 160,
+                    fg: RGB::from_f32(1.0, 1.0, 1.0),
+                    bg: RGB::from_f32(0., 0., 0.),
+                })
+                .with(Viewshed{ visible_tiles : Vec::new(), range: 8, dirty: true })
+                .with(Monster{})
+                .with(Name{ name: format!("Vault Goblin #{}", i) })
+                .with(BlocksTile{})
+                .with(CombatStats{ max_hp: 16, hp: 16, defense: 1, power: 4 })
+                .build();
+        } else if name == "Health Potion" {
+            gs.ecs.create_entity()
+                .with(Position{ x, y })
+                .with(Renderable{
+                    glyph: rltk::to_cp437('!'),
+                    fg: RGB::from_f32(1.0, 0.0, 1.0),
+                    bg: RGB::from_f32(0., 0., 0.),
+                })
+                .with(Name{ name: "Health Potion".to_string() })
+                .with(Item{})
+                .with(ProvidesHealing{ heal_amount: 8 })
+                .build();
+        }
+    }
+
+    for room in map.rooms.iter().skip(1) {
+        if rng.roll_dice(1, 3) != 1 { continue; }
+
+        let (center_x, center_y) = room.center();
+        let x = if center_x + 1 < room.x2 { center_x + 1 } else { center_x };
+        let y = center_y;
+        gs.ecs.create_entity()
+            .with(Position{ x, y })
+            .with(Renderable{
+                glyph: rltk::to_cp437('!'),
+                fg: RGB::from_f32(1.0, 0.0, 1.0),
+                bg: RGB::from_f32(0., 0., 0.),
+            })
+            .with(Name{ name: "Health Potion".to_string() })
+            .with(Item{})
+            .with(ProvidesHealing{ heal_amount: 8 })
             .build();
     }
 
     gs.ecs.insert(map);
     gs.ecs.insert(Point::new(player_x, player_y));
+    gs.ecs.insert(player_entity);
+    gs.ecs.insert(RunState::Running);
+    gs.ecs.insert(MonsterTurnGate::default());
     rltk::main_loop(context, gs)
 }
\ No newline at end of file
@@ -0,0 +1,94 @@
+use super::{Map, Rect, Position};
+use rltk::RandomNumberGenerator;
+
+mod simple_map;
+use simple_map::SimpleMapBuilder;
+mod prefab_builder;
+use prefab_builder::PrefabBuilder;
+
+/// Set this to true to push a tile snapshot after every carving step, letting
+/// `RunState::MapGeneration` step through how the dungeon was built.
+pub const SHOW_MAPGEN_VISUALIZER: bool = false;
+
+/// Shared, in-progress state threaded through a `BuilderChain`. Each builder
+/// reads and mutates this in place rather than returning a new `Map`.
+pub struct BuilderMap {
+    pub map: Map,
+    pub rooms: Vec<Rect>,
+    pub starting_position: Option<Position>,
+    pub spawn_list: Vec<(usize, String)>,
+    pub history: Vec<Map>,
+}
+
+impl BuilderMap {
+    fn take_snapshot(&mut self) {
+        if SHOW_MAPGEN_VISUALIZER {
+            let mut snapshot = self.map.clone();
+            for v in snapshot.revealed_tiles.iter_mut() { *v = true; }
+            self.history.push(snapshot);
+        }
+    }
+}
+
+/// The first step of a chain: carves a map out of nothing.
+pub trait InitialMapBuilder {
+    fn build_map(&mut self, rng: &mut RandomNumberGenerator, build_data: &mut BuilderMap);
+}
+
+/// A transform that runs on top of an already-built map.
+pub trait MetaMapBuilder {
+    fn build_map(&mut self, rng: &mut RandomNumberGenerator, build_data: &mut BuilderMap);
+}
+
+pub struct BuilderChain {
+    starter: Option<Box<dyn InitialMapBuilder>>,
+    builders: Vec<Box<dyn MetaMapBuilder>>,
+    pub build_data: BuilderMap,
+}
+
+impl BuilderChain {
+    pub fn new(width: i32, height: i32) -> BuilderChain {
+        BuilderChain {
+            starter: None,
+            builders: Vec::new(),
+            build_data: BuilderMap {
+                map: Map::new(width, height),
+                rooms: Vec::new(),
+                starting_position: None,
+                spawn_list: Vec::new(),
+                history: Vec::new(),
+            },
+        }
+    }
+
+    pub fn start_with(&mut self, starter: Box<dyn InitialMapBuilder>) {
+        match self.starter {
+            None => self.starter = Some(starter),
+            Some(_) => panic!("A BuilderChain can only have one starting builder"),
+        }
+    }
+
+    pub fn with(&mut self, metabuilder: Box<dyn MetaMapBuilder>) {
+        self.builders.push(metabuilder);
+    }
+
+    pub fn build_map(&mut self, rng: &mut RandomNumberGenerator) {
+        match &mut self.starter {
+            None => panic!("Cannot run a BuilderChain without a starting builder"),
+            Some(starter) => starter.build_map(rng, &mut self.build_data),
+        }
+
+        for metabuilder in self.builders.iter_mut() {
+            metabuilder.build_map(rng, &mut self.build_data);
+        }
+    }
+}
+
+/// The default chain: rooms and corridors, with hand-authored vaults stamped
+/// into whatever open floor space is left.
+pub fn random_builder(width: i32, height: i32) -> BuilderChain {
+    let mut chain = BuilderChain::new(width, height);
+    chain.start_with(SimpleMapBuilder::new());
+    chain.with(PrefabBuilder::room_vaults());
+    chain
+}
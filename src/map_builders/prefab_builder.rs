@@ -0,0 +1,192 @@
+use super::{BuilderMap, MetaMapBuilder};
+use crate::TileType;
+use rltk::RandomNumberGenerator;
+use std::collections::{HashSet, VecDeque};
+
+/// `(width, height, layout)` - each row of `layout` must be exactly `width`
+/// characters: `#` wall, `.` floor, `g` goblin spawn, `%` item spawn.
+pub type PrefabTemplate = (i32, i32, &'static str);
+
+#[rustfmt::skip]
+const GOBLIN_VAULT: PrefabTemplate = (9, 7, concat!(
+    "#########",
+    "#.......#",
+    "#..ggg..#",
+    "#..g.g..#",
+    "#..ggg..#",
+    "#...%...#",
+    "####.####",
+));
+
+#[rustfmt::skip]
+pub const ENTRYWAY: PrefabTemplate = (11, 7, concat!(
+    "###########",
+    "#.........#",
+    "#.#######.#",
+    "#.#.....#.#",
+    "#.#.%...#.#",
+    "#.#######.#",
+    "###########",
+));
+
+#[derive(Clone, Copy)]
+enum PrefabMode {
+    RoomVaults,
+    Constant(PrefabTemplate),
+}
+
+pub struct PrefabBuilder {
+    mode: PrefabMode,
+}
+
+impl MetaMapBuilder for PrefabBuilder {
+    fn build_map(&mut self, _rng: &mut RandomNumberGenerator, build_data: &mut BuilderMap) {
+        match self.mode {
+            PrefabMode::RoomVaults => self.apply_room_vaults(build_data),
+            PrefabMode::Constant(template) => self.apply_constant(template, build_data),
+        }
+    }
+}
+
+impl PrefabBuilder {
+    /// Stamps hand-authored rooms into any open floor space large enough to hold them.
+    pub fn room_vaults() -> Box<PrefabBuilder> {
+        Box::new(PrefabBuilder { mode: PrefabMode::RoomVaults })
+    }
+
+    /// Overwrites the whole level with one large, fixed template.
+    pub fn constant(template: PrefabTemplate) -> Box<PrefabBuilder> {
+        Box::new(PrefabBuilder { mode: PrefabMode::Constant(template) })
+    }
+
+    fn apply_room_vaults(&mut self, build_data: &mut BuilderMap) {
+        let mut used_tiles: HashSet<usize> = HashSet::new();
+
+        for template in [GOBLIN_VAULT].iter() {
+            let (width, height, layout) = *template;
+            if let Some((origin_x, origin_y)) = find_free_region(width, height, &used_tiles, build_data) {
+                let before = build_data.map.clone();
+                let spawn_list_len = build_data.spawn_list.len();
+                let mut touched: HashSet<usize> = HashSet::new();
+
+                stamp(origin_x, origin_y, width, height, layout, &mut touched, build_data);
+
+                if connectivity_preserved(&before, build_data) {
+                    used_tiles.extend(touched);
+                } else {
+                    build_data.map = before;
+                    build_data.spawn_list.truncate(spawn_list_len);
+                }
+            }
+        }
+
+        build_data.map.populate_blocked();
+        build_data.take_snapshot();
+    }
+
+    fn apply_constant(&mut self, template: PrefabTemplate, build_data: &mut BuilderMap) {
+        let (width, height, layout) = template;
+        let mut used_tiles: HashSet<usize> = HashSet::new();
+        stamp(0, 0, width, height, layout, &mut used_tiles, build_data);
+        build_data.map.populate_blocked();
+        build_data.take_snapshot();
+    }
+}
+
+/// Returns true if every floor tile reachable from the starting position
+/// before the stamp, and still floor afterward, is still reachable, so a
+/// vault's walls can't sever a corridor and strand the player or a room.
+/// No starting position yet means there's nothing to validate against.
+fn connectivity_preserved(before: &crate::Map, build_data: &BuilderMap) -> bool {
+    let start = match build_data.starting_position {
+        Some(pos) => before.xy_idx(pos.x, pos.y),
+        None => return true,
+    };
+
+    let reachable_before = reachable_floor(before, start);
+    let reachable_after = reachable_floor(&build_data.map, start);
+    (0..before.tiles.len()).all(|idx| {
+        !reachable_before[idx] || build_data.map.tiles[idx] != TileType::Floor || reachable_after[idx]
+    })
+}
+
+fn reachable_floor(map: &crate::Map, start_idx: usize) -> Vec<bool> {
+    let mut seen = vec![false; map.tiles.len()];
+    if map.tiles[start_idx] != TileType::Floor { return seen; }
+
+    let mut queue = VecDeque::new();
+    seen[start_idx] = true;
+    queue.push_back(start_idx);
+
+    while let Some(idx) = queue.pop_front() {
+        let x = idx as i32 % map.width;
+        let y = idx as i32 / map.width;
+
+        for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1)].iter() {
+            let (nx, ny) = (x + dx, y + dy);
+            if nx < 0 || nx >= map.width || ny < 0 || ny >= map.height { continue; }
+
+            let nidx = map.xy_idx(nx, ny);
+            if !seen[nidx] && map.tiles[nidx] == TileType::Floor {
+                seen[nidx] = true;
+                queue.push_back(nidx);
+            }
+        }
+    }
+
+    seen
+}
+
+/// Scans for an open rectangular region of floor tiles large enough to hold
+/// the template, skipping anything already claimed by an earlier vault.
+fn find_free_region(width: i32, height: i32, used: &HashSet<usize>, build_data: &BuilderMap) -> Option<(i32, i32)> {
+    let map = &build_data.map;
+
+    for y in 1..(map.height - height - 1) {
+        for x in 1..(map.width - width - 1) {
+            let mut fits = true;
+            'scan: for ty in 0..height {
+                for tx in 0..width {
+                    let idx = map.xy_idx(x + tx, y + ty);
+                    if map.tiles[idx] != TileType::Floor || used.contains(&idx) {
+                        fits = false;
+                        break 'scan;
+                    }
+                }
+            }
+            if fits { return Some((x, y)); }
+        }
+    }
+
+    None
+}
+
+fn stamp(origin_x: i32, origin_y: i32, width: i32, height: i32, layout: &str, used: &mut HashSet<usize>, build_data: &mut BuilderMap) {
+    for ty in 0..height {
+        let row_start = (ty * width) as usize;
+        let row = &layout[row_start..row_start + width as usize];
+
+        for (tx, glyph) in row.chars().enumerate() {
+            let x = origin_x + tx as i32;
+            let y = origin_y + ty;
+            if x < 0 || x >= build_data.map.width || y < 0 || y >= build_data.map.height { continue; }
+
+            let idx = build_data.map.xy_idx(x, y);
+            used.insert(idx);
+
+            match glyph {
+                '#' => build_data.map.tiles[idx] = TileType::Wall,
+                '.' => build_data.map.tiles[idx] = TileType::Floor,
+                'g' => {
+                    build_data.map.tiles[idx] = TileType::Floor;
+                    build_data.spawn_list.push((idx, "Goblin".to_string()));
+                }
+                '%' => {
+                    build_data.map.tiles[idx] = TileType::Floor;
+                    build_data.spawn_list.push((idx, "Health Potion".to_string()));
+                }
+                _ => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use super::{BuilderMap, InitialMapBuilder};
+use crate::{Rect, TileType, Position, Map};
+use rltk::RandomNumberGenerator;
+use std::cmp::{max, min};
+
+pub struct SimpleMapBuilder {}
+
+impl InitialMapBuilder for SimpleMapBuilder {
+    fn build_map(&mut self, rng: &mut RandomNumberGenerator, build_data: &mut BuilderMap) {
+        self.rooms_and_corridors(rng, build_data);
+    }
+}
+
+impl SimpleMapBuilder {
+    pub fn new() -> Box<SimpleMapBuilder> {
+        Box::new(SimpleMapBuilder {})
+    }
+
+    /// Ports the original rooms-and-corridors algorithm from
+    /// http://rogueliketutorials.com/tutorials/tcod/part-3/
+    fn rooms_and_corridors(&mut self, rng: &mut RandomNumberGenerator, build_data: &mut BuilderMap) {
+        const MAX_ROOMS: i32 = 30;
+        const MIN_SIZE: i32 = 6;
+        const MAX_SIZE: i32 = 10;
+
+        for _ in 0..MAX_ROOMS {
+            let w = rng.range(MIN_SIZE, MAX_SIZE);
+            let h = rng.range(MIN_SIZE, MAX_SIZE);
+            let x = rng.roll_dice(1, build_data.map.width - w - 1) - 1;
+            let y = rng.roll_dice(1, build_data.map.height - h - 1) - 1;
+            let new_room = Rect::new(x, y, w, h);
+            let mut ok = true;
+
+            for other_room in build_data.map.rooms.iter() {
+                if new_room.intersect(other_room) { ok = false }
+            }
+
+            if ok {
+                apply_room_to_map(&mut build_data.map, &new_room);
+                build_data.take_snapshot();
+
+                if !build_data.map.rooms.is_empty() {
+                    let (new_x, new_y) = new_room.center();
+                    let (prev_x, prev_y) = build_data.map.rooms[build_data.map.rooms.len() - 1].center();
+                    if rng.range(0, 2) == 1 {
+                        apply_horizontal_tunnel(&mut build_data.map, prev_x, new_x, prev_y);
+                        apply_vertical_tunnel(&mut build_data.map, prev_y, new_y, prev_x);
+                    } else {
+                        apply_vertical_tunnel(&mut build_data.map, prev_y, new_y, prev_x);
+                        apply_horizontal_tunnel(&mut build_data.map, prev_x, new_x, new_y);
+                    }
+                    build_data.take_snapshot();
+                }
+
+                build_data.map.rooms.push(new_room);
+            }
+        }
+
+        if let Some(room) = build_data.map.rooms.first() {
+            let (x, y) = room.center();
+            build_data.starting_position = Some(Position { x, y });
+        }
+
+        build_data.rooms = build_data.map.rooms.clone();
+        build_data.map.populate_blocked();
+    }
+}
+
+fn apply_room_to_map(map: &mut Map, room: &Rect) {
+    for y in room.y1 + 1..=room.y2 {
+        for x in room.x1 + 1..=room.x2 {
+            let idx = map.xy_idx(x, y);
+            map.tiles[idx] = TileType::Floor;
+        }
+    }
+}
+
+fn apply_horizontal_tunnel(map: &mut Map, x1: i32, x2: i32, y: i32) {
+    for x in min(x1, x2)..=max(x1, x2) {
+        let idx = map.xy_idx(x, y);
+        if idx > 0 && idx < map.tiles.len() {
+            map.tiles[idx] = TileType::Floor;
+        }
+    }
+}
+
+fn apply_vertical_tunnel(map: &mut Map, y1: i32, y2: i32, x: i32) {
+    for y in min(y1, y2)..=max(y1, y2) {
+        let idx = map.xy_idx(x, y);
+        if idx > 0 && idx < map.tiles.len() {
+            map.tiles[idx] = TileType::Floor;
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use specs::prelude::*;
+use super::{WantsToMelee, Name, CombatStats, SufferDamage};
+
+pub struct MeleeCombatSystem {}
+
+impl<'a> System<'a> for MeleeCombatSystem {
+    type SystemData = ( Entities<'a>,
+                        WriteStorage<'a, WantsToMelee>,
+                        ReadStorage<'a, Name>,
+                        ReadStorage<'a, CombatStats>,
+                        WriteStorage<'a, SufferDamage>);
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (entities, mut wants_melee, names, combat_stats, mut inflict_damage) = data;
+
+        for (entity, wants_melee, name, stats) in (&entities, &wants_melee, &names, &combat_stats).join() {
+            if stats.hp > 0 {
+                let target_stats = combat_stats.get(wants_melee.target);
+                if let Some(target_stats) = target_stats {
+                    if target_stats.hp > 0 {
+                        let damage = i32::max(0, stats.power - target_stats.defense);
+                        let target_name = names.get(wants_melee.target).map_or("it", |n| n.name.as_str());
+
+                        if damage == 0 {
+                            println!("{} is unable to hurt {}", name.name, target_name);
+                        } else {
+                            println!("{} hits {} for {} hp", name.name, target_name, damage);
+                            SufferDamage::new_damage(&mut inflict_damage, wants_melee.target, damage);
+                        }
+                    }
+                }
+            }
+        }
+
+        wants_melee.clear();
+    }
+}
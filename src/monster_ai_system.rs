@@ -1,21 +1,62 @@
 use specs::prelude::*;
-use super::{ Viewshed, Monster };
-use rltk::{ Point };
+use super::{Viewshed, Monster, Position, Map, WantsToMelee, RunState};
+use rltk::{Point, a_star_search};
+use std::collections::HashSet;
+
+/// Tracks which monsters have already taken their turn for the current
+/// `RunState::Running` pass, so a monster can't act twice in one player turn.
+pub struct MonsterTurnGate {
+    acted: HashSet<Entity>,
+}
+
+impl Default for MonsterTurnGate {
+    fn default() -> Self {
+        MonsterTurnGate { acted: HashSet::new() }
+    }
+}
 
 pub struct MonsterAI {}
 
 impl<'a> System<'a> for MonsterAI {
-    type SystemData = ( ReadExpect<'a, Point>,
-                        ReadStorage<'a, Viewshed>,
-                        ReadStorage<'a, Monster>);
+    type SystemData = ( WriteExpect<'a, Map>,
+                        ReadExpect<'a, Point>,
+                        ReadExpect<'a, Entity>,
+                        ReadExpect<'a, RunState>,
+                        WriteExpect<'a, MonsterTurnGate>,
+                        Entities<'a>,
+                        WriteStorage<'a, Viewshed>,
+                        WriteStorage<'a, Position>,
+                        ReadStorage<'a, Monster>,
+                        WriteStorage<'a, WantsToMelee>);
 
     fn run(&mut self, data : Self::SystemData) {
-        let (player_pos, viewshed, monster) = data;
+        let (mut map, player_pos, player_entity, runstate, mut turn_gate, entities, mut viewshed, mut position, monster, mut wants_to_melee) = data;
+
+        if *runstate != RunState::Running { return; }
+
+        turn_gate.acted.clear();
+
+        for (entity, viewshed, pos, _monster) in (&entities, &mut viewshed, &mut position, &monster).join() {
+            if !turn_gate.acted.insert(entity) { continue; }
+            if !viewshed.visible_tiles.contains(&*player_pos) { continue; }
+
+            let distance = rltk::DistanceAlg::Pythagoras.distance2d(Point::new(pos.x, pos.y), *player_pos);
+            if distance < 1.5 {
+                wants_to_melee.insert(entity, WantsToMelee { target: *player_entity }).expect("Unable to insert melee intent");
+                continue;
+            }
 
-        for (viewshed, _monster) in (&viewshed, &monster).join() {
-            if viewshed.visible_tiles.contains(&*player_pos) {
-                // console::log(format!("{} Monster shouts insults", name.name));
+            let monster_idx = map.xy_idx(pos.x, pos.y);
+            let player_idx = map.xy_idx(player_pos.x, player_pos.y);
+            let path = a_star_search(monster_idx, player_idx, &*map);
+            if path.success && path.steps.len() > 1 {
+                let next_idx = path.steps[1];
+                pos.x = next_idx as i32 % map.width;
+                pos.y = next_idx as i32 / map.width;
+                map.blocked[monster_idx] = false;
+                map.blocked[next_idx] = true;
+                viewshed.dirty = true;
             }
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,79 @@
+use rltk::{VirtualKeyCode, Rltk, Point};
+use specs::prelude::*;
+use std::cmp::{min, max};
+use super::{Position, Player, Viewshed, Map, State, RunState, CombatStats, WantsToMelee, Item, WantsToPickupItem};
+
+pub fn try_move_player(delta_x: i32, delta_y: i32, ecs: &mut World) {
+    let mut positions = ecs.write_storage::<Position>();
+    let mut players = ecs.write_storage::<Player>();
+    let mut viewsheds = ecs.write_storage::<Viewshed>();
+    let combat_stats = ecs.read_storage::<CombatStats>();
+    let map = ecs.fetch::<Map>();
+    let entities = ecs.entities();
+    let mut wants_to_melee = ecs.write_storage::<WantsToMelee>();
+
+    for (entity, _player, pos, viewshed) in (&entities, &mut players, &mut positions, &mut viewsheds).join() {
+        let dest_x = pos.x + delta_x;
+        let dest_y = pos.y + delta_y;
+        if dest_x < 1 || dest_x > map.width - 1 || dest_y < 1 || dest_y > map.height - 1 { return; }
+        let destination_idx = map.xy_idx(dest_x, dest_y);
+
+        for potential_target in map.tile_content[destination_idx].iter() {
+            if combat_stats.get(*potential_target).is_some() {
+                wants_to_melee.insert(entity, WantsToMelee { target: *potential_target }).expect("Add target failed");
+                return;
+            }
+        }
+
+        if !map.blocked[destination_idx] {
+            pos.x = min(map.width - 1, max(0, dest_x));
+            pos.y = min(map.height - 1, max(0, dest_y));
+            viewshed.dirty = true;
+
+            let mut ppos = ecs.write_resource::<Point>();
+            ppos.x = pos.x;
+            ppos.y = pos.y;
+        }
+    }
+}
+
+fn get_item(ecs: &mut World) {
+    let player_pos = ecs.fetch::<Point>();
+    let player_entity = *ecs.fetch::<Entity>();
+    let entities = ecs.entities();
+    let items = ecs.read_storage::<Item>();
+    let positions = ecs.read_storage::<Position>();
+
+    let mut target_item = None;
+    for (item_entity, _item, position) in (&entities, &items, &positions).join() {
+        if position.x == player_pos.x && position.y == player_pos.y {
+            target_item = Some(item_entity);
+        }
+    }
+
+    match target_item {
+        None => println!("There is nothing here to pick up"),
+        Some(item) => {
+            let mut pickup = ecs.write_storage::<WantsToPickupItem>();
+            pickup.insert(player_entity, WantsToPickupItem { collected_by: player_entity, item })
+                .expect("Unable to insert want to pickup");
+        }
+    }
+}
+
+pub fn player_input(gs: &mut State, ctx: &mut Rltk) -> RunState {
+    match ctx.key {
+        None => return RunState::Paused,
+        Some(key) => match key {
+            VirtualKeyCode::Left | VirtualKeyCode::H => try_move_player(-1, 0, &mut gs.ecs),
+            VirtualKeyCode::Right | VirtualKeyCode::L => try_move_player(1, 0, &mut gs.ecs),
+            VirtualKeyCode::Up | VirtualKeyCode::K => try_move_player(0, -1, &mut gs.ecs),
+            VirtualKeyCode::Down | VirtualKeyCode::J => try_move_player(0, 1, &mut gs.ecs),
+            VirtualKeyCode::G => get_item(&mut gs.ecs),
+            VirtualKeyCode::I => return RunState::ShowInventory,
+            VirtualKeyCode::D => return RunState::ShowDropItem,
+            _ => return RunState::Paused,
+        },
+    }
+    RunState::Running
+}